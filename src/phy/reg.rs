@@ -4,6 +4,27 @@ pub trait Register: From<u16> + Into<u16> {
     const ADDRESS: u8;
 }
 
+/// Access to a PHY's MDIO-addressable registers, abstracting over the
+/// underlying bus.
+///
+/// `Phy` implements this in terms of its own `read`/`modify`. Pulling the
+/// bound out as a trait lets hardware-sequencing logic (MMD indirect
+/// access, autonegotiation polling) be exercised in tests against an
+/// in-memory register file, with no real MDIO hardware involved.
+trait RegAccess {
+    fn read<R: Register>(&self) -> R;
+    fn modify<R: Register>(&self, f: impl FnOnce(&mut R));
+}
+
+impl<'a> RegAccess for Phy<'a> {
+    fn read<R: Register>(&self) -> R {
+        Phy::read(self)
+    }
+    fn modify<R: Register>(&self, f: impl FnOnce(&mut R)) {
+        Phy::modify(self, f);
+    }
+}
+
 /// A macro for declaring and defining the MDIO phy registers.
 macro_rules! impl_phy_registers {
     // Register members.
@@ -38,10 +59,37 @@ macro_rules! impl_phy_registers {
     (reg_member $mask:literal $CONST:ident) => {
         impl_phy_registers!(reg_mask $CONST $mask);
     };
-    (reg_members $($mask:literal $CONST:ident $($methods:ident)*,)*) => {
-        $(
-            impl_phy_registers!(reg_member $mask $CONST $($methods)*);
-        )*
+
+    // Multi-bit register members, e.g. a two-bit speed selector or an MMD
+    // address/control field.
+    (reg_getter_bits $shift:literal $width:literal $getter:ident) => {
+        pub fn $getter(&self) -> u16 {
+            (self.0 >> $shift) & ((1 << $width) - 1)
+        }
+    };
+    (reg_setter_bits $shift:literal $width:literal $setter:ident) => {
+        pub fn $setter(&mut self, value: u16) -> &mut Self {
+            let mask = ((1 << $width) - 1) << $shift;
+            self.0 = (self.0 & !mask) | ((value << $shift) & mask);
+            self
+        }
+    };
+    (reg_member_bits $shift:literal $width:literal $CONST:ident $getter:ident $setter:ident) => {
+        pub const $CONST: u16 = ((1u16 << $width) - 1) << $shift;
+        impl_phy_registers!(reg_getter_bits $shift $width $getter);
+        impl_phy_registers!(reg_setter_bits $shift $width $setter);
+    };
+
+    // Tt-munch the field list one member at a time, since bit-flag members
+    // and multi-bit members don't share a token shape.
+    (reg_members) => {};
+    (reg_members bits $shift:literal $width:literal $CONST:ident $getter:ident $setter:ident, $($rest:tt)*) => {
+        impl_phy_registers!(reg_member_bits $shift $width $CONST $getter $setter);
+        impl_phy_registers!(reg_members $($rest)*);
+    };
+    (reg_members $mask:literal $CONST:ident $($methods:ident)*, $($rest:tt)*) => {
+        impl_phy_registers!(reg_member $mask $CONST $($methods)*);
+        impl_phy_registers!(reg_members $($rest)*);
     };
 
     // `Phy` methods.
@@ -64,11 +112,30 @@ macro_rules! impl_phy_registers {
         impl_phy_registers!(phy_getter $Reg $FIELD $getter);
     };
     (phy_method $Reg:ident $FIELD:ident) => {};
-    (phy_methods $Reg:ident $($mask:literal $FIELD:ident $($methods:ident)*,)*) => {
-        $(
-            impl_phy_registers!(phy_method $Reg $FIELD $($methods)*);
-        )*
 
+    (phy_getter_bits $Reg:ident $getter:ident) => {
+        pub fn $getter(&self) -> u16 {
+            self.read::<$Reg>().$getter()
+        }
+    };
+    (phy_setter_bits $Reg:ident $setter:ident) => {
+        pub fn $setter(&self, value: u16) -> &Self {
+            self.modify(|r: &mut $Reg| { r.$setter(value); })
+        }
+    };
+    (phy_method_bits $Reg:ident $getter:ident $setter:ident) => {
+        impl_phy_registers!(phy_getter_bits $Reg $getter);
+        impl_phy_registers!(phy_setter_bits $Reg $setter);
+    };
+
+    (phy_methods $Reg:ident) => {};
+    (phy_methods $Reg:ident bits $shift:literal $width:literal $CONST:ident $getter:ident $setter:ident, $($rest:tt)*) => {
+        impl_phy_registers!(phy_method_bits $Reg $getter $setter);
+        impl_phy_registers!(phy_methods $Reg $($rest)*);
+    };
+    (phy_methods $Reg:ident $mask:literal $FIELD:ident $($methods:ident)*, $($rest:tt)*) => {
+        impl_phy_registers!(phy_method $Reg $FIELD $($methods)*);
+        impl_phy_registers!(phy_methods $Reg $($rest)*);
     };
 
     // Top-level.
@@ -161,4 +228,633 @@ impl_phy_registers! {
         0x0040 LP_10_FD lp_10_fd,
         0x0020 LP_10_HD lp_10_hd,
     ],
+    0xD Mmdctrl mmdctrl [
+        bits 14 2 FUNCTION function set_function,
+        bits 0 5 DEVAD devad set_devad,
+    ],
+    0xE Mmdad mmdad [],
+}
+
+/// `Mmdctrl::FUNCTION` value selecting address mode, in which `Mmdad` holds
+/// the MMD register address to be accessed.
+const MMDCTRL_FUNCTION_ADDRESS: u16 = 0b00;
+
+/// `Mmdctrl::FUNCTION` value selecting data mode without post-increment, in
+/// which `Mmdad` holds the value at the previously addressed MMD register.
+const MMDCTRL_FUNCTION_DATA_NO_POST_INCREMENT: u16 = 0b01;
+
+/// Select the MMD `devad`/`reg` pair, leaving `Mmdctrl` in data mode so the
+/// next `Mmdad` access reads or writes the targeted value.
+fn mmd_select<T: RegAccess>(bus: &T, devad: u8, reg: u16) {
+    bus.modify(|r: &mut Mmdctrl| {
+        r.set_function(MMDCTRL_FUNCTION_ADDRESS);
+        r.set_devad(devad as u16);
+    });
+    bus.modify(|r: &mut Mmdad| {
+        r.0 = reg;
+    });
+    bus.modify(|r: &mut Mmdctrl| {
+        r.set_function(MMDCTRL_FUNCTION_DATA_NO_POST_INCREMENT);
+        r.set_devad(devad as u16);
+    });
+}
+
+/// The pure register sequence behind [`Phy::read_mmd`], split out so it can
+/// be unit tested against an in-memory [`RegAccess`] with no real MDIO
+/// hardware.
+fn read_mmd_via<T: RegAccess>(bus: &T, devad: u8, reg: u16) -> u16 {
+    mmd_select(bus, devad, reg);
+    bus.read::<Mmdad>().0
+}
+
+/// The pure register sequence behind [`Phy::write_mmd`].
+fn write_mmd_via<T: RegAccess>(bus: &T, devad: u8, reg: u16, val: u16) {
+    mmd_select(bus, devad, reg);
+    bus.modify(|r: &mut Mmdad| {
+        r.0 = val;
+    });
+}
+
+impl<'a> Phy<'a> {
+    /// Read a register behind Clause 45 MMD indirect addressing.
+    ///
+    /// Selects `devad`/`reg` through `Mmdctrl`/`Mmdad` in address mode, then
+    /// switches `Mmdctrl` to data mode and reads the value back through
+    /// `Mmdad`.
+    pub fn read_mmd(&self, devad: u8, reg: u16) -> u16 {
+        read_mmd_via(self, devad, reg)
+    }
+
+    /// Write a register behind Clause 45 MMD indirect addressing.
+    ///
+    /// See [`Phy::read_mmd`] for the indirect addressing sequence.
+    pub fn write_mmd(&self, devad: u8, reg: u16, val: u16) {
+        write_mmd_via(self, devad, reg, val);
+    }
+}
+
+/// The resolved link speed and duplex mode, as determined by either
+/// autonegotiation or the forced `Bcr` settings.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LinkMode {
+    Speed10HalfDuplex,
+    Speed10FullDuplex,
+    Speed100HalfDuplex,
+    Speed100FullDuplex,
+}
+
+/// The pure priority-ordered common-ability resolution behind
+/// [`Phy::resolve_link`], taking already-read registers so it can be unit
+/// tested without a live MDIO-backed `Phy`.
+fn resolve_link_from_regs(bsr: &Bsr, bcr: &Bcr, anar: &Anar, anlpar: &Anlpar) -> Option<LinkMode> {
+    if !bsr.link_status() {
+        return None;
+    }
+
+    if bcr.enable_autoneg() && bsr.an_complete() {
+        let common = anar.0 & anlpar.0;
+        Some(if common & Anar::ADV_100_FD == Anar::ADV_100_FD {
+            LinkMode::Speed100FullDuplex
+        } else if common & Anar::ADV_100_HD == Anar::ADV_100_HD {
+            LinkMode::Speed100HalfDuplex
+        } else if common & Anar::ADV_10_FD == Anar::ADV_10_FD {
+            LinkMode::Speed10FullDuplex
+        } else if common & Anar::ADV_10_HD == Anar::ADV_10_HD {
+            LinkMode::Speed10HalfDuplex
+        } else {
+            return None;
+        })
+    } else {
+        Some(match (bcr.force_100(), bcr.force_fd()) {
+            (true, true) => LinkMode::Speed100FullDuplex,
+            (true, false) => LinkMode::Speed100HalfDuplex,
+            (false, true) => LinkMode::Speed10FullDuplex,
+            (false, false) => LinkMode::Speed10HalfDuplex,
+        })
+    }
+}
+
+impl<'a> Phy<'a> {
+    /// Resolve the currently active link speed and duplex mode.
+    ///
+    /// Returns `None` if the link is down. If autonegotiation is enabled and
+    /// complete, the mode is the highest-priority ability common to both the
+    /// local (`Anar`) and link-partner (`Anlpar`) advertisements, in the
+    /// order 100 FD > 100 HD > 10 FD > 10 HD. Otherwise it falls back to the
+    /// forced settings in `Bcr`.
+    pub fn resolve_link(&self) -> Option<LinkMode> {
+        resolve_link_from_regs(&self.bsr(), &self.bcr(), &self.anar(), &self.anlpar())
+    }
+
+    /// The 32-bit PHY identifier, assembled from `Phyidr1` and `Phyidr2` as
+    /// `(phyidr1 << 16) | phyidr2`.
+    ///
+    /// Encodes the IEEE 802.3 OUI, model number and revision, and can be
+    /// matched against known chips (e.g. LAN8742, DP83848, KSZ8081) at
+    /// runtime.
+    pub fn phy_id(&self) -> u32 {
+        phy_id_from_regs(self.phyidr1().0, self.phyidr2().0)
+    }
+
+    /// The 22-bit IEEE OUI, i.e. bits 31..10 of `phy_id`.
+    pub fn oui(&self) -> u32 {
+        oui_of_phy_id(self.phy_id())
+    }
+
+    /// The 6-bit vendor model number, i.e. bits 9..4 of `Phyidr2`.
+    pub fn model(&self) -> u8 {
+        model_of_phy_id(self.phy_id())
+    }
+
+    /// The 4-bit silicon revision, i.e. bits 3..0 of `Phyidr2`.
+    pub fn revision(&self) -> u8 {
+        revision_of_phy_id(self.phy_id())
+    }
+}
+
+/// The OUI/model/revision-assembling arithmetic behind [`Phy::phy_id`],
+/// taking already-read register values so it can be unit tested without a
+/// live MDIO-backed `Phy`.
+fn phy_id_from_regs(phyidr1: u16, phyidr2: u16) -> u32 {
+    ((phyidr1 as u32) << 16) | (phyidr2 as u32)
+}
+
+/// The pure register logic behind [`Phy::oui`].
+fn oui_of_phy_id(phy_id: u32) -> u32 {
+    phy_id >> 10
+}
+
+/// The pure register logic behind [`Phy::model`].
+fn model_of_phy_id(phy_id: u32) -> u8 {
+    ((phy_id >> 4) & 0x3F) as u8
+}
+
+/// The pure register logic behind [`Phy::revision`].
+fn revision_of_phy_id(phy_id: u32) -> u8 {
+    (phy_id & 0xF) as u8
+}
+
+/// Vendor-specific PHY bring-up, status and autonegotiation behavior.
+///
+/// The core crate only understands the standard register set. Chips that
+/// need magic register sequences, errata workarounds or non-standard status
+/// decoding implement this trait instead of reaching into `Phy` directly;
+/// [`GenericPhyDriver`] provides the standard BCR/BSR/ANAR behavior as a
+/// fallback for chips with no dedicated driver.
+pub trait PhyDriver {
+    /// Whether this driver handles the chip identified by `id` (see
+    /// [`Phy::phy_id`]).
+    fn matches(id: u32) -> bool
+    where
+        Self: Sized;
+
+    /// One-time vendor-specific initialization, run once at bring-up.
+    fn config_init(&self, phy: &Phy) {
+        let _ = phy;
+    }
+
+    /// Resolve the currently active link speed and duplex mode.
+    fn read_status(&self, phy: &Phy) -> Option<LinkMode> {
+        phy.resolve_link()
+    }
+
+    /// Configure and (re)start autonegotiation.
+    fn config_aneg(&self, phy: &Phy) {
+        phy.set_enable_autoneg(true);
+        phy.set_restart_autoneg(true);
+    }
+
+    /// Acknowledge a pending PHY interrupt, for chips that support one.
+    fn ack_interrupt(&self, phy: &Phy) {
+        let _ = phy;
+    }
+
+    /// Configure PHY interrupt generation, for chips that support it.
+    fn config_intr(&self, phy: &Phy) {
+        let _ = phy;
+    }
+}
+
+/// The fallback [`PhyDriver`] used when no vendor driver matches, relying
+/// only on the standard BCR/BSR/ANAR/ANLPAR register behavior.
+pub struct GenericPhyDriver;
+
+impl PhyDriver for GenericPhyDriver {
+    fn matches(_id: u32) -> bool {
+        true
+    }
+}
+
+/// The single [`GenericPhyDriver`] instance used as the registry fallback.
+static GENERIC_PHY_DRIVER: GenericPhyDriver = GenericPhyDriver;
+
+/// A registry entry pairing a [`PhyDriver::matches`] predicate with the
+/// driver instance to dispatch to when it accepts a `phy_id`.
+pub struct DriverEntry {
+    pub matches: fn(u32) -> bool,
+    pub driver: &'static dyn PhyDriver,
+}
+
+/// Select the first entry in `registry` whose `matches` predicate accepts
+/// `id`, falling back to [`GenericPhyDriver`] if none match.
+pub fn select_driver(id: u32, registry: &[DriverEntry]) -> &'static dyn PhyDriver {
+    registry
+        .iter()
+        .find(|entry| (entry.matches)(id))
+        .map(|entry| entry.driver)
+        .unwrap_or(&GENERIC_PHY_DRIVER)
+}
+
+impl<'a> Phy<'a> {
+    /// Select the driver matching this PHY's decoded [`Phy::phy_id`] from
+    /// `registry`, falling back to [`GenericPhyDriver`].
+    pub fn driver(&self, registry: &[DriverEntry]) -> &'static dyn PhyDriver {
+        select_driver(self.phy_id(), registry)
+    }
+}
+
+/// An error returned by [`Phy::restart_autoneg_and_wait`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnegError {
+    /// Autonegotiation did not complete within the given number of polls.
+    Timeout,
+    /// Autonegotiation completed, but the link is down, e.g. no advertised
+    /// ability was common to both ends, or the link dropped between
+    /// completion and resolution.
+    NoLink,
+}
+
+/// The state of an in-progress or completed autonegotiation, as returned by
+/// [`Phy::poll_autoneg`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AnegState {
+    /// The link is down.
+    NoLink,
+    /// Autonegotiation has not yet completed.
+    InProgress,
+    /// Autonegotiation completed, resolving to the given link mode.
+    Complete(LinkMode),
+}
+
+/// The pure register sequence behind [`Phy::restart_autoneg_and_wait`],
+/// split out so it can be unit tested against an in-memory [`RegAccess`]
+/// with no real MDIO hardware.
+fn restart_autoneg_and_wait_via<T: RegAccess>(
+    bus: &T,
+    max_iters: u32,
+) -> Result<LinkMode, AnegError> {
+    bus.modify(|r: &mut Bcr| {
+        r.set_enable_autoneg(true);
+    });
+    bus.modify(|r: &mut Bcr| {
+        r.set_restart_autoneg(true);
+    });
+
+    for _ in 0..max_iters {
+        if bus.read::<Bsr>().an_complete() {
+            let bsr = bus.read::<Bsr>();
+            let bcr = bus.read::<Bcr>();
+            let anar = bus.read::<Anar>();
+            let anlpar = bus.read::<Anlpar>();
+            return resolve_link_from_regs(&bsr, &bcr, &anar, &anlpar).ok_or(AnegError::NoLink);
+        }
+    }
+
+    Err(AnegError::Timeout)
+}
+
+/// The pure register logic behind [`Phy::poll_autoneg`].
+fn poll_autoneg_via<T: RegAccess>(bus: &T) -> AnegState {
+    let bsr = bus.read::<Bsr>();
+    if !bsr.link_status() {
+        return AnegState::NoLink;
+    }
+    if !bsr.an_complete() {
+        return AnegState::InProgress;
+    }
+    let bcr = bus.read::<Bcr>();
+    let anar = bus.read::<Anar>();
+    let anlpar = bus.read::<Anlpar>();
+    match resolve_link_from_regs(&bsr, &bcr, &anar, &anlpar) {
+        Some(mode) => AnegState::Complete(mode),
+        None => AnegState::NoLink,
+    }
+}
+
+impl<'a> Phy<'a> {
+    /// Restart autonegotiation and poll `Bsr::an_complete` up to `max_iters`
+    /// times, mirroring the reset/restart-autoneg bring-up flow.
+    ///
+    /// Returns the resolved link mode on success, `AnegError::Timeout` if
+    /// autonegotiation does not complete within `max_iters` polls, or
+    /// `AnegError::NoLink` if it completes but the link is down.
+    pub fn restart_autoneg_and_wait(&self, max_iters: u32) -> Result<LinkMode, AnegError> {
+        restart_autoneg_and_wait_via(self, max_iters)
+    }
+
+    /// Check autonegotiation status without blocking, for use on
+    /// cooperative schedulers.
+    pub fn poll_autoneg(&self) -> AnegState {
+        poll_autoneg_via(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An in-memory stand-in for the MDIO register file, indexed by
+    /// [`Register::ADDRESS`] (a 5-bit address space), so hardware-sequencing
+    /// logic can be exercised without real MDIO hardware.
+    struct TestBus {
+        regs: core::cell::Cell<[u16; 32]>,
+    }
+
+    impl TestBus {
+        fn new() -> Self {
+            Self {
+                regs: core::cell::Cell::new([0u16; 32]),
+            }
+        }
+
+        fn get_raw(&self, addr: u8) -> u16 {
+            self.regs.get()[addr as usize]
+        }
+
+        fn set_raw(&self, addr: u8, value: u16) {
+            let mut regs = self.regs.get();
+            regs[addr as usize] = value;
+            self.regs.set(regs);
+        }
+    }
+
+    impl RegAccess for TestBus {
+        fn read<R: Register>(&self) -> R {
+            R::from(self.get_raw(R::ADDRESS))
+        }
+
+        fn modify<R: Register>(&self, f: impl FnOnce(&mut R)) {
+            let mut r = self.read::<R>();
+            f(&mut r);
+            self.set_raw(R::ADDRESS, r.into());
+        }
+    }
+
+    #[test]
+    fn read_mmd_selects_devad_and_reg_then_reads_mmdad() {
+        let bus = TestBus::new();
+
+        let value = read_mmd_via(&bus, 0x07, 0x0012);
+
+        // With no real MMD device behind the bus, the value observed
+        // through `Mmdad` is whatever the addressing sequence last wrote
+        // there, i.e. the target register number; this still lets us
+        // assert that `reg` was wired through the sequence correctly.
+        assert_eq!(value, 0x0012);
+        // Mmdctrl must be left in data-no-post-increment mode for `devad`.
+        let mmdctrl = bus.read::<Mmdctrl>();
+        assert_eq!(mmdctrl.function(), MMDCTRL_FUNCTION_DATA_NO_POST_INCREMENT);
+        assert_eq!(mmdctrl.devad(), 0x07);
+    }
+
+    #[test]
+    fn write_mmd_selects_devad_and_reg_then_writes_mmdad() {
+        let bus = TestBus::new();
+
+        write_mmd_via(&bus, 0x03, 0x0100, 0xBEEF);
+
+        assert_eq!(bus.read::<Mmdad>().0, 0xBEEF);
+        let mmdctrl = bus.read::<Mmdctrl>();
+        assert_eq!(mmdctrl.function(), MMDCTRL_FUNCTION_DATA_NO_POST_INCREMENT);
+        assert_eq!(mmdctrl.devad(), 0x03);
+    }
+
+    struct DriverA;
+    impl PhyDriver for DriverA {
+        fn matches(_id: u32) -> bool {
+            false
+        }
+    }
+    static DRIVER_A: DriverA = DriverA;
+
+    struct DriverB;
+    impl PhyDriver for DriverB {
+        fn matches(_id: u32) -> bool {
+            true
+        }
+    }
+    static DRIVER_B: DriverB = DriverB;
+
+    fn driver_ptr(driver: &'static dyn PhyDriver) -> *const () {
+        driver as *const dyn PhyDriver as *const ()
+    }
+
+    #[test]
+    fn select_driver_picks_first_matching_entry() {
+        let registry = [
+            DriverEntry {
+                matches: |_| false,
+                driver: &DRIVER_A,
+            },
+            DriverEntry {
+                matches: |_| true,
+                driver: &DRIVER_B,
+            },
+        ];
+        let selected = select_driver(0x1234, &registry);
+        assert_eq!(driver_ptr(selected), driver_ptr(&DRIVER_B));
+    }
+
+    #[test]
+    fn select_driver_falls_back_to_generic_when_registry_empty() {
+        let registry: [DriverEntry; 0] = [];
+        let selected = select_driver(0x1234, &registry);
+        assert_eq!(driver_ptr(selected), driver_ptr(&GENERIC_PHY_DRIVER));
+    }
+
+    #[test]
+    fn select_driver_falls_back_to_generic_when_none_match() {
+        let registry = [DriverEntry {
+            matches: |_| false,
+            driver: &DRIVER_A,
+        }];
+        let selected = select_driver(0x1234, &registry);
+        assert_eq!(driver_ptr(selected), driver_ptr(&GENERIC_PHY_DRIVER));
+    }
+
+    #[test]
+    fn resolve_link_no_link() {
+        let bsr = Bsr(0x0000);
+        let bcr = Bcr(0x0000);
+        let anar = Anar(0x0000);
+        let anlpar = Anlpar(0x0000);
+        assert_eq!(resolve_link_from_regs(&bsr, &bcr, &anar, &anlpar), None);
+    }
+
+    #[test]
+    fn resolve_link_forced_100_fd() {
+        let bsr = Bsr(Bsr::LINK_STATUS);
+        let mut bcr = Bcr(0x0000);
+        bcr.set_force_100(true);
+        bcr.set_force_fd(true);
+        let anar = Anar(0x0000);
+        let anlpar = Anlpar(0x0000);
+        assert_eq!(
+            resolve_link_from_regs(&bsr, &bcr, &anar, &anlpar),
+            Some(LinkMode::Speed100FullDuplex)
+        );
+    }
+
+    #[test]
+    fn resolve_link_forced_10_hd() {
+        let bsr = Bsr(Bsr::LINK_STATUS);
+        let bcr = Bcr(0x0000);
+        let anar = Anar(0x0000);
+        let anlpar = Anlpar(0x0000);
+        assert_eq!(
+            resolve_link_from_regs(&bsr, &bcr, &anar, &anlpar),
+            Some(LinkMode::Speed10HalfDuplex)
+        );
+    }
+
+    #[test]
+    fn resolve_link_autoneg_picks_highest_common_ability() {
+        let bsr = Bsr(Bsr::LINK_STATUS | Bsr::AN_COMPLETE);
+        let mut bcr = Bcr(0x0000);
+        bcr.set_enable_autoneg(true);
+        // Local advertises 100 HD and 10 HD, but not 100 FD; partner
+        // advertises the same, so the common ability should resolve to the
+        // highest shared mode, 100 HD.
+        let mut anar = Anar(0x0000);
+        anar.set_adv_100_hd(true);
+        anar.set_adv_10_hd(true);
+        let mut anlpar = Anlpar(0x0000);
+        anlpar.0 |= Anlpar::LP_100_HD | Anlpar::LP_10_HD;
+        assert_eq!(
+            resolve_link_from_regs(&bsr, &bcr, &anar, &anlpar),
+            Some(LinkMode::Speed100HalfDuplex)
+        );
+    }
+
+    #[test]
+    fn resolve_link_autoneg_complete_with_no_common_ability() {
+        let bsr = Bsr(Bsr::LINK_STATUS | Bsr::AN_COMPLETE);
+        let mut bcr = Bcr(0x0000);
+        bcr.set_enable_autoneg(true);
+        let mut anar = Anar(0x0000);
+        anar.set_adv_100_fd(true);
+        let anlpar = Anlpar(0x0000);
+        assert_eq!(resolve_link_from_regs(&bsr, &bcr, &anar, &anlpar), None);
+    }
+
+    #[test]
+    fn phy_id_assembles_and_splits_id1_id2() {
+        // A synthetic (not a real vendor's) id: OUI 0x12344 (22 bits),
+        // model 0x2A, revision 0x3.
+        let phyidr1: u16 = 0x048D;
+        let phyidr2: u16 = 0x12A3;
+        let id = phy_id_from_regs(phyidr1, phyidr2);
+        assert_eq!(id, 0x048D12A3);
+        assert_eq!(oui_of_phy_id(id), 0x12344);
+        assert_eq!(model_of_phy_id(id), 0x2A);
+        assert_eq!(revision_of_phy_id(id), 0x3);
+    }
+
+    #[test]
+    fn mmdctrl_bits_round_trip() {
+        let mut mmdctrl = Mmdctrl(0x0000);
+        mmdctrl.set_function(MMDCTRL_FUNCTION_ADDRESS);
+        mmdctrl.set_devad(0x03);
+        assert_eq!(mmdctrl.function(), MMDCTRL_FUNCTION_ADDRESS);
+        assert_eq!(mmdctrl.devad(), 0x03);
+        assert_eq!(mmdctrl.0, 0x0003);
+
+        mmdctrl.set_function(MMDCTRL_FUNCTION_DATA_NO_POST_INCREMENT);
+        assert_eq!(mmdctrl.function(), MMDCTRL_FUNCTION_DATA_NO_POST_INCREMENT);
+        // Changing FUNCTION must not disturb the already-set DEVAD bits.
+        assert_eq!(mmdctrl.devad(), 0x03);
+        assert_eq!(mmdctrl.0, 0x4003);
+    }
+
+    #[test]
+    fn mmdctrl_devad_is_masked_to_5_bits() {
+        let mut mmdctrl = Mmdctrl(0x0000);
+        // DEVAD is 5 bits wide; a wider value must be masked, not bleed
+        // into the FUNCTION field above it.
+        mmdctrl.set_devad(0xFF);
+        assert_eq!(mmdctrl.devad(), 0x1F);
+        assert_eq!(mmdctrl.function(), 0);
+    }
+
+    #[test]
+    fn restart_autoneg_and_wait_resolves_on_first_poll_that_completes() {
+        let bus = TestBus::new();
+        bus.set_raw(Bsr::ADDRESS, Bsr::LINK_STATUS | Bsr::AN_COMPLETE);
+        bus.set_raw(Anar::ADDRESS, Anar::ADV_100_FD);
+        bus.set_raw(Anlpar::ADDRESS, Anlpar::LP_100_FD);
+
+        let result = restart_autoneg_and_wait_via(&bus, 5);
+
+        assert_eq!(result, Ok(LinkMode::Speed100FullDuplex));
+        // The routine must itself enable and (re)start autonegotiation.
+        let bcr = bus.read::<Bcr>();
+        assert!(bcr.enable_autoneg());
+        assert!(bcr.restart_autoneg());
+    }
+
+    #[test]
+    fn restart_autoneg_and_wait_times_out_if_never_complete() {
+        let bus = TestBus::new();
+        bus.set_raw(Bsr::ADDRESS, Bsr::LINK_STATUS);
+
+        let result = restart_autoneg_and_wait_via(&bus, 3);
+
+        assert_eq!(result, Err(AnegError::Timeout));
+    }
+
+    #[test]
+    fn restart_autoneg_and_wait_reports_no_link_when_complete_but_link_down() {
+        let bus = TestBus::new();
+        bus.set_raw(Bsr::ADDRESS, Bsr::AN_COMPLETE);
+
+        let result = restart_autoneg_and_wait_via(&bus, 3);
+
+        assert_eq!(result, Err(AnegError::NoLink));
+    }
+
+    #[test]
+    fn poll_autoneg_no_link() {
+        let bus = TestBus::new();
+        assert_eq!(poll_autoneg_via(&bus), AnegState::NoLink);
+    }
+
+    #[test]
+    fn poll_autoneg_in_progress() {
+        let bus = TestBus::new();
+        bus.set_raw(Bsr::ADDRESS, Bsr::LINK_STATUS);
+        assert_eq!(poll_autoneg_via(&bus), AnegState::InProgress);
+    }
+
+    #[test]
+    fn poll_autoneg_complete() {
+        let bus = TestBus::new();
+        bus.set_raw(Bsr::ADDRESS, Bsr::LINK_STATUS | Bsr::AN_COMPLETE);
+        bus.set_raw(Bcr::ADDRESS, Bcr::ENABLE_AUTONEG);
+        bus.set_raw(Anar::ADDRESS, Anar::ADV_100_FD);
+        bus.set_raw(Anlpar::ADDRESS, Anlpar::LP_100_FD);
+        assert_eq!(
+            poll_autoneg_via(&bus),
+            AnegState::Complete(LinkMode::Speed100FullDuplex)
+        );
+    }
+
+    #[test]
+    fn poll_autoneg_complete_with_no_common_ability_is_no_link() {
+        let bus = TestBus::new();
+        bus.set_raw(Bsr::ADDRESS, Bsr::LINK_STATUS | Bsr::AN_COMPLETE);
+        bus.set_raw(Bcr::ADDRESS, Bcr::ENABLE_AUTONEG);
+        bus.set_raw(Anar::ADDRESS, Anar::ADV_100_FD);
+        // Partner advertises nothing in common with `Anar`.
+        assert_eq!(poll_autoneg_via(&bus), AnegState::NoLink);
+    }
 }